@@ -1,13 +1,117 @@
 use std::error::Error;
 
-pub(crate) fn get_script_name() -> Result<Option<String>, Box<dyn Error>> {
-    let args: Vec<String> = std::env::args().collect();
-    match args.len() {
-        2 => {
-            let file_name = args[1].clone();
-            Ok(Some(file_name))
+#[derive(Debug, PartialEq)]
+pub(crate) enum Mode {
+    Run,
+    Tokens,
+    Ast,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct CliOptions {
+    pub(crate) script: Option<String>,
+    pub(crate) mode: Mode,
+}
+
+pub(crate) fn get_cli_options() -> Result<CliOptions, Box<dyn Error>> {
+    parse_args(&std::env::args().collect::<Vec<String>>())
+}
+
+fn parse_args(args: &[String]) -> Result<CliOptions, Box<dyn Error>> {
+    let mut mode = Mode::Run;
+    let mut script = None;
+
+    for arg in &args[1..] {
+        match arg.as_str() {
+            "-t" | "--tokens" => mode = Mode::Tokens,
+            "-a" | "--ast" => mode = Mode::Ast,
+            s if s.starts_with('-') => {
+                return Err(format!(
+                    "Usage: {} [-t|--tokens] [-a|--ast] [script]",
+                    args[0]
+                )
+                .into())
+            }
+            _ if script.is_none() => script = Some(arg.clone()),
+            _ => {
+                return Err(format!(
+                    "Usage: {} [-t|--tokens] [-a|--ast] [script]",
+                    args[0]
+                )
+                .into())
+            }
         }
-        1 => Ok(None),
-        _ => Err(format!("Usage: {} [script]", args[0]).into()),
+    }
+
+    Ok(CliOptions { script, mode })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(raw: &[&str]) -> Vec<String> {
+        raw.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn no_args_means_repl_mode_with_no_script() {
+        let opts = parse_args(&args(&["rlox"])).unwrap();
+        assert_eq!(
+            opts,
+            CliOptions {
+                script: None,
+                mode: Mode::Run,
+            }
+        );
+    }
+
+    #[test]
+    fn script_path_is_picked_up() {
+        let opts = parse_args(&args(&["rlox", "main.lox"])).unwrap();
+        assert_eq!(
+            opts,
+            CliOptions {
+                script: Some("main.lox".to_string()),
+                mode: Mode::Run,
+            }
+        );
+    }
+
+    #[test]
+    fn tokens_flag_selects_tokens_mode() {
+        let opts = parse_args(&args(&["rlox", "--tokens", "main.lox"])).unwrap();
+        assert_eq!(
+            opts,
+            CliOptions {
+                script: Some("main.lox".to_string()),
+                mode: Mode::Tokens,
+            }
+        );
+
+        let opts = parse_args(&args(&["rlox", "-t"])).unwrap();
+        assert_eq!(opts.mode, Mode::Tokens);
+    }
+
+    #[test]
+    fn ast_flag_selects_ast_mode() {
+        let opts = parse_args(&args(&["rlox", "-a", "main.lox"])).unwrap();
+        assert_eq!(
+            opts,
+            CliOptions {
+                script: Some("main.lox".to_string()),
+                mode: Mode::Ast,
+            }
+        );
+    }
+
+    #[test]
+    fn second_positional_argument_is_an_error() {
+        assert!(parse_args(&args(&["rlox", "a.lox", "b.lox"])).is_err());
+    }
+
+    #[test]
+    fn unrecognized_flag_is_an_error() {
+        assert!(parse_args(&args(&["rlox", "-x", "main.lox"])).is_err());
     }
 }
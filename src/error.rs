@@ -14,4 +14,20 @@ pub enum LoxError {
 
     #[error("{}", format_err(line, "Unterminated float".to_string()))]
     UnterminatedFloat { line: usize },
+
+    #[error("{}", format_err(line, "Unterminated comment".to_string()))]
+    UnterminatedComment { line: usize },
+
+    #[error("{}", format_err(line, format!("Invalid escape sequence: \\{}", seq)))]
+    InvalidEscape { line: usize, seq: String },
+
+    #[error("{}", format_err(line, message.clone()))]
+    ParseError {
+        line: usize,
+        message: String,
+        at_eof: bool,
+    },
+
+    #[error("{}", format_err(line, message.clone()))]
+    RuntimeError { line: usize, message: String },
 }
@@ -0,0 +1,65 @@
+use crate::error::LoxError;
+use crate::interpreter::value::Value;
+use crate::lexer::token::Token;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// A lexical scope: a flat map of bindings plus an optional link to the
+/// enclosing scope. Lookup and assignment walk the parent chain outward;
+/// `define` always inserts into the innermost scope.
+pub struct Environment {
+    values: HashMap<String, Value>,
+    parent: Option<Rc<RefCell<Environment>>>,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Environment {
+            values: HashMap::new(),
+            parent: None,
+        }
+    }
+
+    pub fn with_parent(parent: Rc<RefCell<Environment>>) -> Self {
+        Environment {
+            values: HashMap::new(),
+            parent: Some(parent),
+        }
+    }
+
+    pub fn define(&mut self, name: String, value: Value) {
+        self.values.insert(name, value);
+    }
+
+    pub fn get(&self, name: &Token) -> Result<Value, LoxError> {
+        if let Some(value) = self.values.get(name.lexeme()) {
+            return Ok(value.clone());
+        }
+
+        if let Some(parent) = &self.parent {
+            return parent.borrow().get(name);
+        }
+
+        Err(LoxError::RuntimeError {
+            line: name.line(),
+            message: format!("Undefined variable '{}'.", name.lexeme()),
+        })
+    }
+
+    pub fn assign(&mut self, name: &Token, value: Value) -> Result<(), LoxError> {
+        if self.values.contains_key(name.lexeme()) {
+            self.values.insert(name.lexeme().to_string(), value);
+            return Ok(());
+        }
+
+        if let Some(parent) = &self.parent {
+            return parent.borrow_mut().assign(name, value);
+        }
+
+        Err(LoxError::RuntimeError {
+            line: name.line(),
+            message: format!("Undefined variable '{}'.", name.lexeme()),
+        })
+    }
+}
@@ -0,0 +1,427 @@
+pub mod environment;
+pub mod value;
+
+use crate::error::LoxError;
+use crate::interpreter::environment::Environment;
+use crate::interpreter::value::{Callable, Value};
+use crate::lexer::token::{Token, TokenType};
+use crate::parser::ast::{Expr, LiteralValue, Stmt};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Non-local control flow raised while executing a statement: either a plain
+/// runtime error, or a `return` unwinding to the enclosing function call.
+enum Unwind {
+    Error(LoxError),
+    Return(Value),
+}
+
+impl From<LoxError> for Unwind {
+    fn from(error: LoxError) -> Self {
+        Unwind::Error(error)
+    }
+}
+
+pub struct Interpreter {
+    environment: Rc<RefCell<Environment>>,
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Interpreter {
+    pub fn new() -> Self {
+        let globals = Rc::new(RefCell::new(Environment::new()));
+        globals.borrow_mut().define(
+            "clock".to_string(),
+            Value::Callable(Callable::Builtin {
+                name: "clock",
+                arity: 0,
+                function: &clock,
+            }),
+        );
+
+        Interpreter {
+            environment: globals,
+        }
+    }
+
+    pub fn interpret(&mut self, statements: &[Stmt]) -> Result<(), LoxError> {
+        for stmt in statements {
+            match self.execute(stmt) {
+                Ok(()) | Err(Unwind::Return(_)) => {}
+                Err(Unwind::Error(e)) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Run a function body in a fresh scope chained to its closure, unwinding
+    /// an `Unwind::Return` into the function's result value.
+    pub(crate) fn call_function(
+        &mut self,
+        body: &[Stmt],
+        environment: Rc<RefCell<Environment>>,
+    ) -> Result<Value, LoxError> {
+        match self.execute_block(body, environment) {
+            Ok(()) => Ok(Value::Nil),
+            Err(Unwind::Return(value)) => Ok(value),
+            Err(Unwind::Error(e)) => Err(e),
+        }
+    }
+
+    fn execute_block(
+        &mut self,
+        statements: &[Stmt],
+        environment: Rc<RefCell<Environment>>,
+    ) -> Result<(), Unwind> {
+        let previous = std::mem::replace(&mut self.environment, environment);
+
+        let result = statements.iter().try_for_each(|stmt| self.execute(stmt));
+
+        self.environment = previous;
+        result
+    }
+
+    fn execute(&mut self, stmt: &Stmt) -> Result<(), Unwind> {
+        match stmt {
+            Stmt::Expression(expr) => {
+                self.evaluate(expr)?;
+                Ok(())
+            }
+            Stmt::Print(expr) => {
+                let value = self.evaluate(expr)?;
+                println!("{}", value);
+                Ok(())
+            }
+            Stmt::Var { name, initializer } => {
+                let value = match initializer {
+                    Some(expr) => self.evaluate(expr)?,
+                    None => Value::Nil,
+                };
+                self.environment
+                    .borrow_mut()
+                    .define(name.lexeme().to_string(), value);
+                Ok(())
+            }
+            Stmt::Block(statements) => {
+                let scope = Rc::new(RefCell::new(Environment::with_parent(
+                    self.environment.clone(),
+                )));
+                self.execute_block(statements, scope)
+            }
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                if is_truthy(&self.evaluate(condition)?) {
+                    self.execute(then_branch)
+                } else if let Some(else_branch) = else_branch {
+                    self.execute(else_branch)
+                } else {
+                    Ok(())
+                }
+            }
+            Stmt::While { condition, body } => {
+                while is_truthy(&self.evaluate(condition)?) {
+                    self.execute(body)?;
+                }
+                Ok(())
+            }
+            Stmt::Function { name, params, body } => {
+                let function = Callable::Function {
+                    name: name.clone(),
+                    params: params.clone(),
+                    body: body.clone(),
+                    closure: self.environment.clone(),
+                };
+                self.environment
+                    .borrow_mut()
+                    .define(name.lexeme().to_string(), Value::Callable(function));
+                Ok(())
+            }
+            Stmt::Return { value, .. } => {
+                let value = match value {
+                    Some(expr) => self.evaluate(expr)?,
+                    None => Value::Nil,
+                };
+                Err(Unwind::Return(value))
+            }
+        }
+    }
+
+    fn evaluate(&mut self, expr: &Expr) -> Result<Value, LoxError> {
+        match expr {
+            Expr::Literal { value } => Ok(match value {
+                LiteralValue::Number(n) => Value::Number(*n),
+                LiteralValue::Str(s) => Value::Str(s.clone()),
+                LiteralValue::Bool(b) => Value::Bool(*b),
+                LiteralValue::Nil => Value::Nil,
+            }),
+            Expr::Grouping { expression } => self.evaluate(expression),
+            Expr::Variable { name } => self.environment.borrow().get(name),
+            Expr::Assign { name, value } => {
+                let value = self.evaluate(value)?;
+                self.environment.borrow_mut().assign(name, value.clone())?;
+                Ok(value)
+            }
+            Expr::Unary { operator, right } => {
+                let right = self.evaluate(right)?;
+                match operator.token_type() {
+                    TokenType::Minus => Ok(Value::Number(-as_number(&right, operator)?)),
+                    TokenType::Bang => Ok(Value::Bool(!is_truthy(&right))),
+                    _ => unreachable!("invalid unary operator"),
+                }
+            }
+            Expr::Logical {
+                left,
+                operator,
+                right,
+            } => {
+                let left = self.evaluate(left)?;
+                match operator.token_type() {
+                    TokenType::Or if is_truthy(&left) => Ok(left),
+                    TokenType::And if !is_truthy(&left) => Ok(left),
+                    TokenType::Or | TokenType::And => self.evaluate(right),
+                    _ => unreachable!("invalid logical operator"),
+                }
+            }
+            Expr::Binary {
+                left,
+                operator,
+                right,
+            } => {
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+                match operator.token_type() {
+                    TokenType::Minus => {
+                        Ok(Value::Number(as_number(&left, operator)? - as_number(&right, operator)?))
+                    }
+                    TokenType::Slash => {
+                        Ok(Value::Number(as_number(&left, operator)? / as_number(&right, operator)?))
+                    }
+                    TokenType::Star => {
+                        Ok(Value::Number(as_number(&left, operator)? * as_number(&right, operator)?))
+                    }
+                    TokenType::Greater => {
+                        Ok(Value::Bool(as_number(&left, operator)? > as_number(&right, operator)?))
+                    }
+                    TokenType::GreaterEqual => {
+                        Ok(Value::Bool(as_number(&left, operator)? >= as_number(&right, operator)?))
+                    }
+                    TokenType::Less => {
+                        Ok(Value::Bool(as_number(&left, operator)? < as_number(&right, operator)?))
+                    }
+                    TokenType::LessEqual => {
+                        Ok(Value::Bool(as_number(&left, operator)? <= as_number(&right, operator)?))
+                    }
+                    TokenType::Plus => match (left, right) {
+                        (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a + b)),
+                        (Value::Str(a), Value::Str(b)) => Ok(Value::Str(a + &b)),
+                        _ => Err(LoxError::RuntimeError {
+                            line: operator.line(),
+                            message: "Operands must be two numbers or two strings.".to_string(),
+                        }),
+                    },
+                    TokenType::BangEqual => Ok(Value::Bool(left != right)),
+                    TokenType::EqualEqual => Ok(Value::Bool(left == right)),
+                    _ => unreachable!("invalid binary operator"),
+                }
+            }
+            Expr::Call {
+                callee,
+                paren,
+                arguments,
+            } => {
+                let callee = self.evaluate(callee)?;
+                let mut args = Vec::with_capacity(arguments.len());
+                for argument in arguments {
+                    args.push(self.evaluate(argument)?);
+                }
+
+                match callee {
+                    Value::Callable(callable) => {
+                        if args.len() != callable.arity() {
+                            return Err(LoxError::RuntimeError {
+                                line: paren.line(),
+                                message: format!(
+                                    "Expected {} arguments but got {}.",
+                                    callable.arity(),
+                                    args.len()
+                                ),
+                            });
+                        }
+                        callable.call(self, args)
+                    }
+                    _ => Err(LoxError::RuntimeError {
+                        line: paren.line(),
+                        message: "Can only call functions and classes.".to_string(),
+                    }),
+                }
+            }
+        }
+    }
+}
+
+/// Only `nil` and `false` are falsy; everything else, including `0`, is truthy.
+fn is_truthy(value: &Value) -> bool {
+    !matches!(value, Value::Nil | Value::Bool(false))
+}
+
+fn as_number(value: &Value, operator: &Token) -> Result<f64, LoxError> {
+    match value {
+        Value::Number(n) => Ok(*n),
+        _ => Err(LoxError::RuntimeError {
+            line: operator.line(),
+            message: "Operand must be a number.".to_string(),
+        }),
+    }
+}
+
+fn clock(_interpreter: &mut Interpreter, _arguments: Vec<Value>) -> Result<Value, LoxError> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64();
+    Ok(Value::Number(now))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use crate::scanner::Scanner;
+
+    fn eval(source: &str) -> Result<Value, LoxError> {
+        let tokens = Scanner::new(source.to_string()).scan_tokens().unwrap();
+        let statements = Parser::new(tokens).parse().unwrap();
+        let mut interpreter = Interpreter::new();
+        match &statements[..] {
+            [Stmt::Expression(expr)] => interpreter.evaluate(expr),
+            _ => panic!("expected a single expression statement"),
+        }
+    }
+
+    fn run(source: &str) -> Result<(), LoxError> {
+        let tokens = Scanner::new(source.to_string()).scan_tokens().unwrap();
+        let statements = Parser::new(tokens).parse().unwrap();
+        Interpreter::new().interpret(&statements)
+    }
+
+    /// Run `source`, then return the value bound to the global `name`.
+    fn run_and_get(source: &str, name: &str) -> Value {
+        let tokens = Scanner::new(source.to_string()).scan_tokens().unwrap();
+        let statements = Parser::new(tokens).parse().unwrap();
+        let mut interpreter = Interpreter::new();
+        interpreter.interpret(&statements).unwrap();
+        let name = Token::new(TokenType::Identifier, name.to_string(), 1);
+        let value = interpreter.environment.borrow().get(&name).unwrap();
+        value
+    }
+
+    #[test]
+    fn evaluates_arithmetic_with_precedence() {
+        assert_eq!(eval("1 + 2 * 3;").unwrap(), Value::Number(7.0));
+    }
+
+    #[test]
+    fn evaluates_hex_and_binary_literals() {
+        assert_eq!(eval("0xFF;").unwrap(), Value::Number(255.0));
+        assert_eq!(eval("0b101;").unwrap(), Value::Number(5.0));
+    }
+
+    #[test]
+    fn concatenates_strings_with_plus() {
+        assert_eq!(
+            eval(r#""foo" + "bar";"#).unwrap(),
+            Value::Str("foobar".to_string())
+        );
+    }
+
+    #[test]
+    fn adding_number_and_string_is_a_runtime_error() {
+        let err = eval(r#"1 + "a";"#).unwrap_err();
+        assert_eq!(
+            err,
+            LoxError::RuntimeError {
+                line: 1,
+                message: "Operands must be two numbers or two strings.".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn only_nil_and_false_are_falsy() {
+        assert_eq!(eval("!nil;").unwrap(), Value::Bool(true));
+        assert_eq!(eval("!false;").unwrap(), Value::Bool(true));
+        assert_eq!(eval("!0;").unwrap(), Value::Bool(false));
+        assert_eq!(eval(r#"!"";"#).unwrap(), Value::Bool(false));
+    }
+
+    #[test]
+    fn and_or_short_circuit() {
+        // `nope` is undefined, so evaluating the right operand would be a runtime error.
+        assert_eq!(eval("false and nope;").unwrap(), Value::Bool(false));
+        assert_eq!(eval("true or nope;").unwrap(), Value::Bool(true));
+    }
+
+    #[test]
+    fn variables_are_scoped_to_their_block() {
+        assert_eq!(run_and_get("var a = 1; { var a = 2; }", "a"), Value::Number(1.0));
+    }
+
+    #[test]
+    fn assignment_mutates_the_nearest_binding() {
+        assert_eq!(run_and_get("var a = 1; { a = 2; }", "a"), Value::Number(2.0));
+    }
+
+    #[test]
+    fn undefined_variable_is_a_runtime_error() {
+        let err = run("print nope;").unwrap_err();
+        assert_eq!(
+            err,
+            LoxError::RuntimeError {
+                line: 1,
+                message: "Undefined variable 'nope'.".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn while_loop_runs_until_condition_is_false() {
+        assert_eq!(
+            run_and_get("var i = 0; while (i < 3) { i = i + 1; }", "i"),
+            Value::Number(3.0)
+        );
+    }
+
+    #[test]
+    fn function_call_returns_value() {
+        assert_eq!(
+            run_and_get("fun add(a, b) { return a + b; } var sum = add(1, 2);", "sum"),
+            Value::Number(3.0)
+        );
+    }
+
+    #[test]
+    fn calling_a_non_callable_is_a_runtime_error() {
+        let err = run("var a = 1; a();").unwrap_err();
+        assert_eq!(
+            err,
+            LoxError::RuntimeError {
+                line: 1,
+                message: "Can only call functions and classes.".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn clock_builtin_is_available_and_callable() {
+        assert!(run("clock();").is_ok());
+    }
+}
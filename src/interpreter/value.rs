@@ -0,0 +1,104 @@
+use crate::error::LoxError;
+use crate::interpreter::environment::Environment;
+use crate::interpreter::Interpreter;
+use crate::lexer::token::Token;
+use crate::parser::ast::Stmt;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+pub type BuiltinFn = dyn Fn(&mut Interpreter, Vec<Value>) -> Result<Value, LoxError>;
+
+#[derive(Clone)]
+pub enum Value {
+    Number(f64),
+    Str(String),
+    Bool(bool),
+    Nil,
+    Callable(Callable),
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a == b,
+            (Value::Str(a), Value::Str(b)) => a == b,
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::Nil, Value::Nil) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Str(s) => write!(f, "{}", s),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Nil => write!(f, "nil"),
+            Value::Callable(c) => write!(f, "<fn {}>", c.name()),
+        }
+    }
+}
+
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Callable(c) => write!(f, "Callable({})", c.name()),
+            other => write!(f, "{}", other),
+        }
+    }
+}
+
+/// Something that can be invoked with `(...)`: either a builtin backed by a
+/// Rust function, or a user-defined `fun` closing over the environment it was
+/// declared in.
+#[derive(Clone)]
+pub enum Callable {
+    Builtin {
+        name: &'static str,
+        arity: usize,
+        function: &'static BuiltinFn,
+    },
+    Function {
+        name: Token,
+        params: Vec<Token>,
+        body: Vec<Stmt>,
+        closure: Rc<RefCell<Environment>>,
+    },
+}
+
+impl Callable {
+    pub fn name(&self) -> &str {
+        match self {
+            Callable::Builtin { name, .. } => name,
+            Callable::Function { name, .. } => name.lexeme(),
+        }
+    }
+
+    pub fn arity(&self) -> usize {
+        match self {
+            Callable::Builtin { arity, .. } => *arity,
+            Callable::Function { params, .. } => params.len(),
+        }
+    }
+
+    pub fn call(&self, interpreter: &mut Interpreter, arguments: Vec<Value>) -> Result<Value, LoxError> {
+        match self {
+            Callable::Builtin { function, .. } => function(interpreter, arguments),
+            Callable::Function {
+                params,
+                body,
+                closure,
+                ..
+            } => {
+                let env = Rc::new(RefCell::new(Environment::with_parent(closure.clone())));
+                for (param, argument) in params.iter().zip(arguments) {
+                    env.borrow_mut().define(param.lexeme().to_string(), argument);
+                }
+                interpreter.call_function(body, env)
+            }
+        }
+    }
+}
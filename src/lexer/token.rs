@@ -126,6 +126,18 @@ impl Token {
         };
         Ok(Token::new(type_, lexeme.to_string(), line))
     }
+
+    pub fn token_type(&self) -> &TokenType {
+        &self.type_
+    }
+
+    pub fn lexeme(&self) -> &str {
+        &self.lexeme
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
 }
 
 #[cfg(test)]
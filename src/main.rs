@@ -1,3 +1,4 @@
+extern crate rustyline;
 extern crate strum;
 #[macro_use]
 extern crate strum_macros;
@@ -5,29 +6,52 @@ extern crate thiserror;
 
 mod cmdline_args;
 mod error;
+mod interpreter;
 mod lexer;
+mod parser;
 mod scanner;
 mod utils;
 
+use crate::cmdline_args::{get_cli_options, Mode};
+use crate::error::LoxError;
+use crate::interpreter::Interpreter;
+use crate::parser::Parser;
 use crate::scanner::Scanner;
-use cmdline_args::get_script_name;
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use std::cell::RefCell;
 use std::error::Error;
+use std::fmt;
 use std::fs::read_to_string;
-use std::io;
-use std::io::stdin;
+use std::path::PathBuf;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let lox = Lox::new();
+/// The parser's collected errors, kept structured (rather than flattened into
+/// a `String`) so the REPL can still inspect the individual `LoxError`s to
+/// decide whether the input is merely incomplete.
+#[derive(Debug)]
+struct ParseErrors(Vec<LoxError>);
+
+impl fmt::Display for ParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self.0.iter().map(|e| e.to_string()).collect();
+        write!(f, "{}", messages.join("\n"))
+    }
+}
+
+impl Error for ParseErrors {}
 
-    let script_name = match get_script_name() {
-        Ok(m) => m,
+fn main() -> Result<(), Box<dyn Error>> {
+    let options = match get_cli_options() {
+        Ok(options) => options,
         Err(e) => {
             print!("{}", e);
             return Ok(());
         }
     };
 
-    match script_name {
+    let lox = Lox::new(options.mode);
+
+    match options.script {
         Some(file) => lox.run_file(file)?,
         None => lox.run_prompt()?,
     }
@@ -35,11 +59,17 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-struct Lox {}
+struct Lox {
+    interpreter: RefCell<Interpreter>,
+    mode: Mode,
+}
 
 impl Lox {
-    pub fn new() -> Self {
-        Lox {}
+    pub fn new(mode: Mode) -> Self {
+        Lox {
+            interpreter: RefCell::new(Interpreter::new()),
+            mode,
+        }
     }
 
     pub fn run_file(&self, file: String) -> Result<(), Box<dyn Error>> {
@@ -47,29 +77,102 @@ impl Lox {
     }
 
     pub fn run_prompt(&self) -> Result<(), Box<dyn Error>> {
+        let history_path = history_path();
+        let mut editor = DefaultEditor::new()?;
+        let _ = editor.load_history(&history_path);
+
+        let mut buffer = String::new();
+
         loop {
-            print!("> ");
+            let prompt = if buffer.is_empty() { "> " } else { "... " };
 
-            io::Write::flush(&mut io::stdout())?;
-            let mut input = String::new();
-            stdin().read_line(&mut input)?;
+            let line = match editor.readline(prompt) {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => {
+                    buffer.clear();
+                    continue;
+                }
+                Err(ReadlineError::Eof) => break,
+                Err(e) => return Err(e.into()),
+            };
 
-            if input == "exit()\n" {
+            if buffer.is_empty() && line == "exit()" {
                 break;
             }
 
-            match self.run(input) {
-                Ok(_) => {}
-                Err(e) => println!("{}", e),
-            };
+            let _ = editor.add_history_entry(line.as_str());
+            if !buffer.is_empty() {
+                buffer.push('\n');
+            }
+            buffer.push_str(&line);
+
+            match self.run(buffer.clone()) {
+                Ok(_) => buffer.clear(),
+                Err(e) if is_incomplete_input(e.as_ref()) => {}
+                Err(e) => {
+                    println!("{}", e);
+                    buffer.clear();
+                }
+            }
         }
+
+        let _ = editor.save_history(&history_path);
         Ok(())
     }
 
     fn run(&self, source: String) -> Result<(), Box<dyn Error>> {
-        for token in Scanner::new(source).scan_tokens()? {
-            println!("{:?}", token);
+        let tokens = Scanner::new(source).scan_tokens()?;
+
+        if self.mode == Mode::Tokens {
+            for token in &tokens {
+                println!("{:?}", token);
+            }
+            return Ok(());
         }
+
+        let statements = Parser::new(tokens).parse().map_err(ParseErrors)?;
+
+        if self.mode == Mode::Ast {
+            for stmt in &statements {
+                println!("{:#?}", stmt);
+            }
+            return Ok(());
+        }
+
+        self.interpreter.borrow_mut().interpret(&statements)?;
         Ok(())
     }
 }
+
+/// Whether `error` signals that the source ran out before a construct was
+/// closed, rather than a genuine syntax or runtime error — e.g. an
+/// unterminated string or a block whose closing `}` hasn't been typed yet.
+/// The REPL uses this to keep reading continuation lines instead of
+/// reporting the error immediately.
+fn is_incomplete_input(error: &(dyn Error + 'static)) -> bool {
+    if let Some(errors) = error.downcast_ref::<ParseErrors>() {
+        return errors.0.iter().any(is_incomplete_lox_error);
+    }
+
+    match error.downcast_ref::<LoxError>() {
+        Some(e) => is_incomplete_lox_error(e),
+        None => false,
+    }
+}
+
+fn is_incomplete_lox_error(error: &LoxError) -> bool {
+    match error {
+        LoxError::UnterminatedString { .. } => true,
+        LoxError::UnterminatedFloat { .. } => true,
+        LoxError::UnterminatedComment { .. } => true,
+        LoxError::ParseError { at_eof, .. } => *at_eof,
+        _ => false,
+    }
+}
+
+fn history_path() -> PathBuf {
+    match std::env::var("HOME") {
+        Ok(home) => PathBuf::from(home).join(".rlox_history"),
+        Err(_) => PathBuf::from(".rlox_history"),
+    }
+}
@@ -0,0 +1,696 @@
+pub mod ast;
+
+use crate::error::LoxError;
+use crate::lexer::token::{Token, TokenType};
+use crate::parser::ast::{Expr, LiteralValue, Stmt};
+
+pub struct Parser {
+    tokens: Vec<Token>,
+    current: usize,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<Token>) -> Self {
+        Parser { tokens, current: 0 }
+    }
+
+    /// Parse the full token stream into a list of statements, collecting as many
+    /// `LoxError::ParseError`s as possible by synchronizing after each one.
+    pub fn parse(&mut self) -> Result<Vec<Stmt>, Vec<LoxError>> {
+        let mut statements = vec![];
+        let mut errors = vec![];
+
+        while !self.is_at_end() {
+            match self.declaration() {
+                Ok(stmt) => statements.push(stmt),
+                Err(e) => {
+                    errors.push(e);
+                    self.synchronize();
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(statements)
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn declaration(&mut self) -> Result<Stmt, LoxError> {
+        if self.match_types(&[TokenType::Fun]) {
+            return self.function("function");
+        }
+        if self.match_types(&[TokenType::Var]) {
+            return self.var_declaration();
+        }
+        self.statement()
+    }
+
+    fn function(&mut self, kind: &str) -> Result<Stmt, LoxError> {
+        let name = self
+            .consume(TokenType::Identifier, &format!("Expect {} name.", kind))?
+            .clone();
+
+        self.consume(
+            TokenType::LeftParen,
+            &format!("Expect '(' after {} name.", kind),
+        )?;
+        let mut params = vec![];
+        if !self.check(&TokenType::RightParen) {
+            loop {
+                params.push(self.consume(TokenType::Identifier, "Expect parameter name.")?.clone());
+                if !self.match_types(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+        self.consume(TokenType::RightParen, "Expect ')' after parameters.")?;
+
+        self.consume(
+            TokenType::LeftBrace,
+            &format!("Expect '{{' before {} body.", kind),
+        )?;
+        let body = self.block()?;
+
+        Ok(Stmt::Function { name, params, body })
+    }
+
+    fn var_declaration(&mut self) -> Result<Stmt, LoxError> {
+        let name = self
+            .consume(TokenType::Identifier, "Expect variable name.")?
+            .clone();
+
+        let initializer = if self.match_types(&[TokenType::Equal]) {
+            Some(self.expression()?)
+        } else {
+            None
+        };
+
+        self.consume(
+            TokenType::Semicolon,
+            "Expect ';' after variable declaration.",
+        )?;
+        Ok(Stmt::Var { name, initializer })
+    }
+
+    fn statement(&mut self) -> Result<Stmt, LoxError> {
+        if self.match_types(&[TokenType::Print]) {
+            return self.print_statement();
+        }
+        if self.match_types(&[TokenType::If]) {
+            return self.if_statement();
+        }
+        if self.match_types(&[TokenType::While]) {
+            return self.while_statement();
+        }
+        if self.match_types(&[TokenType::Return]) {
+            return self.return_statement();
+        }
+        if self.match_types(&[TokenType::LeftBrace]) {
+            return Ok(Stmt::Block(self.block()?));
+        }
+        self.expression_statement()
+    }
+
+    fn print_statement(&mut self) -> Result<Stmt, LoxError> {
+        let value = self.expression()?;
+        self.consume(TokenType::Semicolon, "Expect ';' after value.")?;
+        Ok(Stmt::Print(value))
+    }
+
+    fn return_statement(&mut self) -> Result<Stmt, LoxError> {
+        let keyword = self.previous().clone();
+        let value = if !self.check(&TokenType::Semicolon) {
+            Some(self.expression()?)
+        } else {
+            None
+        };
+        self.consume(TokenType::Semicolon, "Expect ';' after return value.")?;
+        Ok(Stmt::Return { keyword, value })
+    }
+
+    fn if_statement(&mut self) -> Result<Stmt, LoxError> {
+        self.consume(TokenType::LeftParen, "Expect '(' after 'if'.")?;
+        let condition = self.expression()?;
+        self.consume(TokenType::RightParen, "Expect ')' after if condition.")?;
+
+        let then_branch = Box::new(self.statement()?);
+        let else_branch = if self.match_types(&[TokenType::Else]) {
+            Some(Box::new(self.statement()?))
+        } else {
+            None
+        };
+
+        Ok(Stmt::If {
+            condition,
+            then_branch,
+            else_branch,
+        })
+    }
+
+    fn while_statement(&mut self) -> Result<Stmt, LoxError> {
+        self.consume(TokenType::LeftParen, "Expect '(' after 'while'.")?;
+        let condition = self.expression()?;
+        self.consume(TokenType::RightParen, "Expect ')' after condition.")?;
+        let body = Box::new(self.statement()?);
+
+        Ok(Stmt::While { condition, body })
+    }
+
+    fn block(&mut self) -> Result<Vec<Stmt>, LoxError> {
+        let mut statements = vec![];
+
+        while !self.check(&TokenType::RightBrace) && !self.is_at_end() {
+            statements.push(self.declaration()?);
+        }
+
+        self.consume(TokenType::RightBrace, "Expect '}' after block.")?;
+        Ok(statements)
+    }
+
+    fn expression_statement(&mut self) -> Result<Stmt, LoxError> {
+        let expr = self.expression()?;
+        self.consume(TokenType::Semicolon, "Expect ';' after expression.")?;
+        Ok(Stmt::Expression(expr))
+    }
+
+    fn expression(&mut self) -> Result<Expr, LoxError> {
+        self.assignment()
+    }
+
+    fn assignment(&mut self) -> Result<Expr, LoxError> {
+        let expr = self.or()?;
+
+        if self.match_types(&[TokenType::Equal]) {
+            let equals = self.previous().clone();
+            let value = self.assignment()?;
+
+            if let Expr::Variable { name } = expr {
+                return Ok(Expr::Assign {
+                    name,
+                    value: Box::new(value),
+                });
+            }
+
+            return Err(LoxError::ParseError {
+                line: equals.line(),
+                message: "Invalid assignment target.".to_string(),
+                at_eof: false,
+            });
+        }
+
+        Ok(expr)
+    }
+
+    fn or(&mut self) -> Result<Expr, LoxError> {
+        let mut expr = self.and()?;
+
+        while self.match_types(&[TokenType::Or]) {
+            let operator = self.previous().clone();
+            let right = self.and()?;
+            expr = Expr::Logical {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, LoxError> {
+        let mut expr = self.equality()?;
+
+        while self.match_types(&[TokenType::And]) {
+            let operator = self.previous().clone();
+            let right = self.equality()?;
+            expr = Expr::Logical {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
+
+    fn equality(&mut self) -> Result<Expr, LoxError> {
+        let mut expr = self.comparison()?;
+
+        while self.match_types(&[TokenType::BangEqual, TokenType::EqualEqual]) {
+            let operator = self.previous().clone();
+            let right = self.comparison()?;
+            expr = Expr::Binary {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
+
+    fn comparison(&mut self) -> Result<Expr, LoxError> {
+        let mut expr = self.term()?;
+
+        while self.match_types(&[
+            TokenType::Greater,
+            TokenType::GreaterEqual,
+            TokenType::Less,
+            TokenType::LessEqual,
+        ]) {
+            let operator = self.previous().clone();
+            let right = self.term()?;
+            expr = Expr::Binary {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
+
+    fn term(&mut self) -> Result<Expr, LoxError> {
+        let mut expr = self.factor()?;
+
+        while self.match_types(&[TokenType::Minus, TokenType::Plus]) {
+            let operator = self.previous().clone();
+            let right = self.factor()?;
+            expr = Expr::Binary {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
+
+    fn factor(&mut self) -> Result<Expr, LoxError> {
+        let mut expr = self.unary()?;
+
+        while self.match_types(&[TokenType::Slash, TokenType::Star]) {
+            let operator = self.previous().clone();
+            let right = self.unary()?;
+            expr = Expr::Binary {
+                left: Box::new(expr),
+                operator,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr, LoxError> {
+        if self.match_types(&[TokenType::Bang, TokenType::Minus]) {
+            let operator = self.previous().clone();
+            let right = self.unary()?;
+            return Ok(Expr::Unary {
+                operator,
+                right: Box::new(right),
+            });
+        }
+
+        self.call()
+    }
+
+    fn call(&mut self) -> Result<Expr, LoxError> {
+        let mut expr = self.primary()?;
+
+        loop {
+            if self.match_types(&[TokenType::LeftParen]) {
+                expr = self.finish_call(expr)?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(expr)
+    }
+
+    fn finish_call(&mut self, callee: Expr) -> Result<Expr, LoxError> {
+        let mut arguments = vec![];
+
+        if !self.check(&TokenType::RightParen) {
+            loop {
+                arguments.push(self.expression()?);
+                if !self.match_types(&[TokenType::Comma]) {
+                    break;
+                }
+            }
+        }
+
+        let paren = self
+            .consume(TokenType::RightParen, "Expect ')' after arguments.")?
+            .clone();
+
+        Ok(Expr::Call {
+            callee: Box::new(callee),
+            paren,
+            arguments,
+        })
+    }
+
+    fn primary(&mut self) -> Result<Expr, LoxError> {
+        if self.match_types(&[TokenType::False]) {
+            return Ok(Expr::Literal {
+                value: LiteralValue::Bool(false),
+            });
+        }
+        if self.match_types(&[TokenType::True]) {
+            return Ok(Expr::Literal {
+                value: LiteralValue::Bool(true),
+            });
+        }
+        if self.match_types(&[TokenType::Nil]) {
+            return Ok(Expr::Literal {
+                value: LiteralValue::Nil,
+            });
+        }
+        if self.match_types(&[TokenType::Number]) {
+            let lexeme = self.previous().lexeme().to_string();
+            let line = self.previous().line();
+            let value = parse_number_literal(&lexeme).ok_or_else(|| LoxError::ParseError {
+                line,
+                message: format!("Invalid number literal '{}'.", lexeme),
+                at_eof: false,
+            })?;
+            return Ok(Expr::Literal {
+                value: LiteralValue::Number(value),
+            });
+        }
+        if self.match_types(&[TokenType::String]) {
+            return Ok(Expr::Literal {
+                value: LiteralValue::Str(self.previous().lexeme().to_string()),
+            });
+        }
+        if self.match_types(&[TokenType::Identifier]) {
+            return Ok(Expr::Variable {
+                name: self.previous().clone(),
+            });
+        }
+        if self.match_types(&[TokenType::LeftParen]) {
+            let expr = self.expression()?;
+            self.consume(TokenType::RightParen, "Expect ')' after expression.")?;
+            return Ok(Expr::Grouping {
+                expression: Box::new(expr),
+            });
+        }
+
+        Err(LoxError::ParseError {
+            line: self.peek().line(),
+            message: "Expect expression.".to_string(),
+            at_eof: self.is_at_end(),
+        })
+    }
+
+    /// Discard tokens until we're at a statement boundary, so a single syntax error
+    /// doesn't cascade into a wall of spurious follow-on errors.
+    fn synchronize(&mut self) {
+        self.advance();
+
+        while !self.is_at_end() {
+            if *self.previous().token_type() == TokenType::Semicolon {
+                return;
+            }
+
+            match self.peek().token_type() {
+                TokenType::Class
+                | TokenType::Fun
+                | TokenType::Var
+                | TokenType::For
+                | TokenType::If
+                | TokenType::While
+                | TokenType::Print
+                | TokenType::Return => return,
+                _ => {}
+            }
+
+            self.advance();
+        }
+    }
+
+    fn match_types(&mut self, types: &[TokenType]) -> bool {
+        for type_ in types {
+            if self.check(type_) {
+                self.advance();
+                return true;
+            }
+        }
+        false
+    }
+
+    fn consume(&mut self, type_: TokenType, message: &str) -> Result<&Token, LoxError> {
+        if self.check(&type_) {
+            return Ok(self.advance());
+        }
+
+        Err(LoxError::ParseError {
+            line: self.peek().line(),
+            message: message.to_string(),
+            at_eof: self.is_at_end(),
+        })
+    }
+
+    fn check(&self, type_: &TokenType) -> bool {
+        if self.is_at_end() {
+            false
+        } else {
+            self.peek().token_type() == type_
+        }
+    }
+
+    fn advance(&mut self) -> &Token {
+        if !self.is_at_end() {
+            self.current += 1;
+        }
+        self.previous()
+    }
+
+    fn is_at_end(&self) -> bool {
+        *self.peek().token_type() == TokenType::Eof
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.current]
+    }
+
+    fn previous(&self) -> &Token {
+        &self.tokens[self.current - 1]
+    }
+}
+
+/// Parse a scanned number lexeme into its `f64` value, understanding the
+/// `0x`/`0b` prefixes the scanner accepts alongside plain decimal/scientific
+/// literals.
+fn parse_number_literal(lexeme: &str) -> Option<f64> {
+    if let Some(digits) = lexeme.strip_prefix("0x").or_else(|| lexeme.strip_prefix("0X")) {
+        return i64::from_str_radix(digits, 16).ok().map(|n| n as f64);
+    }
+    if let Some(digits) = lexeme.strip_prefix("0b").or_else(|| lexeme.strip_prefix("0B")) {
+        return i64::from_str_radix(digits, 2).ok().map(|n| n as f64);
+    }
+    lexeme.parse::<f64>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::Scanner;
+
+    fn parse(source: &str) -> Result<Vec<Stmt>, Vec<LoxError>> {
+        let tokens = Scanner::new(source.to_string()).scan_tokens().unwrap();
+        Parser::new(tokens).parse()
+    }
+
+    #[test]
+    fn parse_print_statement() {
+        let stmts = parse("print 1 + 2;").unwrap();
+        assert_eq!(
+            stmts,
+            vec![Stmt::Print(Expr::Binary {
+                left: Box::new(Expr::Literal {
+                    value: LiteralValue::Number(1.0)
+                }),
+                operator: Token::new(TokenType::Plus, "+".into(), 1),
+                right: Box::new(Expr::Literal {
+                    value: LiteralValue::Number(2.0)
+                }),
+            })]
+        );
+    }
+
+    #[test]
+    fn parse_respects_factor_precedence_over_term() {
+        let stmts = parse("1 + 2 * 3;").unwrap();
+        assert_eq!(
+            stmts,
+            vec![Stmt::Expression(Expr::Binary {
+                left: Box::new(Expr::Literal {
+                    value: LiteralValue::Number(1.0)
+                }),
+                operator: Token::new(TokenType::Plus, "+".into(), 1),
+                right: Box::new(Expr::Binary {
+                    left: Box::new(Expr::Literal {
+                        value: LiteralValue::Number(2.0)
+                    }),
+                    operator: Token::new(TokenType::Star, "*".into(), 1),
+                    right: Box::new(Expr::Literal {
+                        value: LiteralValue::Number(3.0)
+                    }),
+                }),
+            })]
+        );
+    }
+
+    #[test]
+    fn parse_var_declaration_with_initializer() {
+        let stmts = parse("var a = 1;").unwrap();
+        assert_eq!(
+            stmts,
+            vec![Stmt::Var {
+                name: Token::new(TokenType::Identifier, "a".into(), 1),
+                initializer: Some(Expr::Literal {
+                    value: LiteralValue::Number(1.0)
+                }),
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_var_declaration_without_initializer() {
+        let stmts = parse("var a;").unwrap();
+        assert_eq!(
+            stmts,
+            vec![Stmt::Var {
+                name: Token::new(TokenType::Identifier, "a".into(), 1),
+                initializer: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_block() {
+        let stmts = parse("{ var a = 1; print a; }").unwrap();
+        assert_eq!(
+            stmts,
+            vec![Stmt::Block(vec![
+                Stmt::Var {
+                    name: Token::new(TokenType::Identifier, "a".into(), 1),
+                    initializer: Some(Expr::Literal {
+                        value: LiteralValue::Number(1.0)
+                    }),
+                },
+                Stmt::Print(Expr::Variable {
+                    name: Token::new(TokenType::Identifier, "a".into(), 1)
+                }),
+            ])]
+        );
+    }
+
+    #[test]
+    fn parse_if_else() {
+        let stmts = parse("if (true) print 1; else print 2;").unwrap();
+        assert_eq!(
+            stmts,
+            vec![Stmt::If {
+                condition: Expr::Literal {
+                    value: LiteralValue::Bool(true)
+                },
+                then_branch: Box::new(Stmt::Print(Expr::Literal {
+                    value: LiteralValue::Number(1.0)
+                })),
+                else_branch: Some(Box::new(Stmt::Print(Expr::Literal {
+                    value: LiteralValue::Number(2.0)
+                }))),
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_while() {
+        let stmts = parse("while (true) print 1;").unwrap();
+        assert_eq!(
+            stmts,
+            vec![Stmt::While {
+                condition: Expr::Literal {
+                    value: LiteralValue::Bool(true)
+                },
+                body: Box::new(Stmt::Print(Expr::Literal {
+                    value: LiteralValue::Number(1.0)
+                })),
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_call_with_arguments() {
+        let stmts = parse("foo(1, 2);").unwrap();
+        assert_eq!(
+            stmts,
+            vec![Stmt::Expression(Expr::Call {
+                callee: Box::new(Expr::Variable {
+                    name: Token::new(TokenType::Identifier, "foo".into(), 1)
+                }),
+                paren: Token::new(TokenType::RightParen, ")".into(), 1),
+                arguments: vec![
+                    Expr::Literal {
+                        value: LiteralValue::Number(1.0)
+                    },
+                    Expr::Literal {
+                        value: LiteralValue::Number(2.0)
+                    },
+                ],
+            })]
+        );
+    }
+
+    #[test]
+    fn parse_function_declaration() {
+        let stmts = parse("fun add(a, b) { return a + b; }").unwrap();
+        assert_eq!(
+            stmts,
+            vec![Stmt::Function {
+                name: Token::new(TokenType::Identifier, "add".into(), 1),
+                params: vec![
+                    Token::new(TokenType::Identifier, "a".into(), 1),
+                    Token::new(TokenType::Identifier, "b".into(), 1),
+                ],
+                body: vec![Stmt::Return {
+                    keyword: Token::new(TokenType::Return, "return".into(), 1),
+                    value: Some(Expr::Binary {
+                        left: Box::new(Expr::Variable {
+                            name: Token::new(TokenType::Identifier, "a".into(), 1)
+                        }),
+                        operator: Token::new(TokenType::Plus, "+".into(), 1),
+                        right: Box::new(Expr::Variable {
+                            name: Token::new(TokenType::Identifier, "b".into(), 1)
+                        }),
+                    }),
+                }],
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_missing_semicolon_reports_error() {
+        let errors = parse("print 1").unwrap_err();
+        assert_eq!(
+            errors,
+            vec![LoxError::ParseError {
+                line: 1,
+                message: "Expect ';' after value.".to_string(),
+                at_eof: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_synchronizes_after_error_to_report_multiple() {
+        let errors = parse("var 1;\nvar 2;\nvar 3;").unwrap_err();
+        assert_eq!(errors.len(), 3);
+    }
+}
@@ -1,9 +1,10 @@
 use crate::error::LoxError;
 use crate::lexer::token::{Token, TokenType};
 use crate::utils::substring;
+use std::str::FromStr;
 
 pub struct Scanner {
-    source: String,
+    chars: Vec<char>,
     start: usize,
     current: usize,
     line: usize,
@@ -12,7 +13,7 @@ pub struct Scanner {
 impl Default for Scanner {
     fn default() -> Self {
         Scanner {
-            source: String::new(),
+            chars: Vec::new(),
             start: 0,
             current: 0,
             line: 1,
@@ -22,9 +23,10 @@ impl Default for Scanner {
 
 impl Scanner {
     pub fn new(source: String) -> Self {
-        let mut scanner = Scanner::default();
-        scanner.source = source;
-        scanner
+        Scanner {
+            chars: source.chars().collect(),
+            ..Scanner::default()
+        }
     }
 
     pub fn scan_tokens(&mut self) -> Result<Vec<Token>, LoxError> {
@@ -59,7 +61,13 @@ impl Scanner {
                     }
                     Ok(None)
                 }
-                None => Ok(Some(Token::build(c.to_string().as_str(), self.line)?)),
+                None => match self.pop_if_exp_is_next('*') {
+                    Some(_) => {
+                        self.parse_block_comment()?;
+                        Ok(None)
+                    }
+                    None => Ok(Some(Token::build(c.to_string().as_str(), self.line)?)),
+                },
             },
             ' ' | '\r' | '\t' => Ok(None),
             '\n' => {
@@ -72,14 +80,17 @@ impl Scanner {
                 self.line,
             ))),
             _ => {
-                if c.is_digit(10) {
+                if c.is_ascii_digit() {
                     Ok(Some(Token::new(
                         TokenType::Number,
                         self.parse_number()?,
                         self.line,
                     )))
-                } else if c.is_alphabetic() {
-                    Ok(Some(Token::build(&self.parse_identifier()?, self.line)?))
+                } else if c.is_alphabetic() || c == '_' {
+                    let lexeme = self.parse_identifier()?;
+                    let type_ =
+                        TokenType::from_str(&lexeme).unwrap_or(TokenType::Identifier);
+                    Ok(Some(Token::new(type_, lexeme, self.line)))
                 } else {
                     Err(LoxError::InvalidToken {
                         line: self.line,
@@ -100,13 +111,18 @@ impl Scanner {
     }
 
     fn peek_next(&mut self) -> Result<char, LoxError> {
-        if self.current + 1 >= self.source.len() {
+        if self.current + 1 >= self.chars.len() {
             Err(LoxError::UnterminatedFloat { line: self.line })
         } else {
             Ok(self.source_at(self.current + 1))
         }
     }
 
+    /// Look `offset` characters ahead of `current` without advancing, returning `'\0'` past the end.
+    fn peek_at(&self, offset: usize) -> char {
+        self.chars.get(self.current + offset).copied().unwrap_or('\0')
+    }
+
     /// Advance the current pointer by one and get the next character in the source
     fn pop(&mut self) -> char {
         self.current += 1;
@@ -123,14 +139,16 @@ impl Scanner {
     }
 
     fn source_at(&self, i: usize) -> char {
-        self.source.as_bytes()[i] as char
+        self.chars[i]
     }
 
     fn is_at_end(&self) -> bool {
-        self.current >= self.source.len()
+        self.current >= self.chars.len()
     }
 
     fn parse_string(&mut self) -> Result<String, LoxError> {
+        let mut value = String::new();
+
         while self.peek() != '"' {
             if self.is_at_end() {
                 return Err(LoxError::UnterminatedString { line: self.line });
@@ -140,34 +158,160 @@ impl Scanner {
                 self.line += 1;
             }
 
-            let _ = self.pop();
+            let c = self.pop();
+            if c == '\\' {
+                value.push(self.parse_escape()?);
+            } else {
+                value.push(c);
+            }
         }
 
         // Advance past the closing "
         self.pop();
 
-        // +1 and -1 for dropping " characters in the lexeme
-        Ok(substring(&self.source, self.start + 1, self.current - 1))
+        Ok(value)
+    }
+
+    /// Parse the character(s) after a `\` inside a string literal.
+    fn parse_escape(&mut self) -> Result<char, LoxError> {
+        if self.is_at_end() {
+            return Err(LoxError::UnterminatedString { line: self.line });
+        }
+
+        match self.pop() {
+            'n' => Ok('\n'),
+            't' => Ok('\t'),
+            'r' => Ok('\r'),
+            '\\' => Ok('\\'),
+            '"' => Ok('"'),
+            'u' => self.parse_unicode_escape(),
+            seq => Err(LoxError::InvalidEscape {
+                line: self.line,
+                seq: seq.to_string(),
+            }),
+        }
+    }
+
+    /// Parse a `\u{...}` escape, where `...` is a hexadecimal Unicode codepoint.
+    fn parse_unicode_escape(&mut self) -> Result<char, LoxError> {
+        if self.is_at_end() || self.pop() != '{' {
+            return Err(LoxError::InvalidEscape {
+                line: self.line,
+                seq: "u".to_string(),
+            });
+        }
+
+        let mut hex = String::new();
+        while self.peek() != '}' {
+            if self.is_at_end() {
+                return Err(LoxError::UnterminatedString { line: self.line });
+            }
+            hex.push(self.pop());
+        }
+        self.pop(); // Consume the closing "}"
+
+        u32::from_str_radix(&hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or(LoxError::InvalidEscape {
+                line: self.line,
+                seq: format!("u{{{}}}", hex),
+            })
     }
 
     fn parse_number(&mut self) -> Result<String, LoxError> {
-        while self.peek().is_digit(10) {
+        if self.chars[self.start] == '0' && matches!(self.peek(), 'x' | 'X') {
             self.pop();
+            while self.peek().is_ascii_hexdigit() || self.peek() == '_' {
+                self.pop();
+            }
+            return Ok(self.numeric_lexeme());
+        }
+
+        if self.chars[self.start] == '0' && matches!(self.peek(), 'b' | 'B') {
+            self.pop();
+            while matches!(self.peek(), '0' | '1' | '_') {
+                self.pop();
+            }
+            return Ok(self.numeric_lexeme());
         }
-        if self.peek() == '.' && self.peek_next()?.is_digit(10) {
+
+        while self.peek().is_ascii_digit() || self.peek() == '_' {
+            self.pop();
+        }
+
+        if self.peek() == '.' && self.peek_next()?.is_ascii_digit() {
             self.pop(); // Consume the "."
-            while self.peek().is_digit(10) {
+            while self.peek().is_ascii_digit() || self.peek() == '_' {
+                self.pop();
+            }
+        }
+
+        if matches!(self.peek(), 'e' | 'E') && self.exponent_has_digits() {
+            self.pop(); // Consume the "e"/"E"
+            if matches!(self.peek(), '+' | '-') {
+                self.pop();
+            }
+            while self.peek().is_ascii_digit() || self.peek() == '_' {
+                self.pop();
+            }
+        }
+
+        Ok(self.numeric_lexeme())
+    }
+
+    /// Look two characters ahead of `current` to see whether an `e`/`E` here
+    /// is scientific notation (optionally signed, followed by a digit) rather
+    /// than the start of an identifier like `1e` on its own.
+    fn exponent_has_digits(&self) -> bool {
+        let signed = matches!(self.peek_at(1), '+' | '-');
+        let digit_offset = if signed { 2 } else { 1 };
+        self.peek_at(digit_offset).is_ascii_digit()
+    }
+
+    /// The numeric lexeme scanned so far, with `_` digit separators stripped.
+    fn numeric_lexeme(&self) -> String {
+        substring(&self.chars, self.start, self.current).replace('_', "")
+    }
+
+    /// Consume a `/* ... */` block comment, which may nest, incrementing
+    /// `self.line` for every newline encountered along the way.
+    fn parse_block_comment(&mut self) -> Result<(), LoxError> {
+        let mut depth = 1;
+
+        while depth > 0 {
+            if self.is_at_end() {
+                return Err(LoxError::UnterminatedComment { line: self.line });
+            }
+
+            if self.peek() == '/' && self.peek_at(1) == '*' {
+                self.pop();
+                self.pop();
+                depth += 1;
+                continue;
+            }
+
+            if self.peek() == '*' && self.peek_at(1) == '/' {
+                self.pop();
                 self.pop();
+                depth -= 1;
+                continue;
             }
+
+            if self.peek() == '\n' {
+                self.line += 1;
+            }
+            self.pop();
         }
-        Ok(substring(&self.source, self.start, self.current))
+
+        Ok(())
     }
 
     fn parse_identifier(&mut self) -> Result<String, LoxError> {
-        while self.peek().is_alphanumeric() {
+        while self.peek().is_alphanumeric() || self.peek() == '_' {
             self.pop();
         }
-        Ok(substring(&self.source, self.start, self.current))
+        Ok(substring(&self.chars, self.start, self.current))
     }
 }
 
@@ -246,6 +390,51 @@ mod tests {
         assert_eq!(exp, act);
     }
 
+    #[test]
+    fn scan_block_comment() {
+        let exp = vec![
+            Token::new(TokenType::Number, "1".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new(r#"/* ignore this */ 1"#.into())
+            .scan_tokens()
+            .unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_nested_block_comment() {
+        let exp = vec![
+            Token::new(TokenType::Number, "1".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new(r#"/* outer /* inner */ still comment */ 1"#.into())
+            .scan_tokens()
+            .unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_block_comment_spanning_multiple_lines() {
+        let exp = vec![
+            Token::new(TokenType::Number, "1".into(), 3),
+            Token::new(TokenType::Eof, "".into(), 3),
+        ];
+        let act = Scanner::new("/* line one\nline two /* nested\nline three */ still */ 1".into())
+            .scan_tokens()
+            .unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_unterminated_block_comment_throws_error() {
+        let exp = Some(LoxError::UnterminatedComment { line: 2 });
+        let act = Scanner::new("/* line one\nline two".into())
+            .scan_tokens()
+            .err();
+        assert_eq!(exp, act);
+    }
+
     #[test]
     fn scan_string() {
         let exp = vec![
@@ -307,6 +496,38 @@ string""#
         assert_eq!(exp, act);
     }
 
+    #[test]
+    fn scan_string_with_escapes() {
+        let exp = vec![
+            Token::new(TokenType::String, "a\tb\nc\\d\"e".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new(r#""a\tb\nc\\d\"e""#.into())
+            .scan_tokens()
+            .unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_string_with_unicode_escape() {
+        let exp = vec![
+            Token::new(TokenType::String, "caf\u{e9}".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new(r#""caf\u{e9}""#.into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_string_with_invalid_escape_throws_error() {
+        let exp = Some(LoxError::InvalidEscape {
+            line: 1,
+            seq: "q".to_string(),
+        });
+        let act = Scanner::new(r#""\q""#.into()).scan_tokens().err();
+        assert_eq!(exp, act);
+    }
+
     #[test]
     fn scan_number() {
         let exp = vec![
@@ -344,6 +565,56 @@ string""#
         assert_eq!(exp, act);
     }
 
+    #[test]
+    fn scan_hex_number() {
+        let exp = vec![
+            Token::new(TokenType::Number, "0xFF".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new(r#"0xFF"#.into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_binary_number() {
+        let exp = vec![
+            Token::new(TokenType::Number, "0b101".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new(r#"0b101"#.into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_number_with_digit_separators() {
+        let exp = vec![
+            Token::new(TokenType::Number, "1000000".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new(r#"1_000_000"#.into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_number_in_scientific_notation() {
+        let exp = vec![
+            Token::new(TokenType::Number, "6.022e23".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new(r#"6.022e23"#.into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_number_in_negative_scientific_notation() {
+        let exp = vec![
+            Token::new(TokenType::Number, "1e-5".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new(r#"1e-5"#.into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
     #[test]
     fn scan_numbers() {
         let exp = vec![
@@ -366,6 +637,16 @@ string""#
         assert_eq!(exp, act);
     }
 
+    #[test]
+    fn scan_user_identifier() {
+        let exp = vec![
+            Token::new(TokenType::Identifier, "foo".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new("foo".into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
     #[test]
     fn scan_identifiers() {
         let exp = vec![
@@ -384,6 +665,46 @@ string""#
         assert_eq!(exp, act);
     }
 
+    #[test]
+    fn scan_string_with_emoji() {
+        let exp = vec![
+            Token::new(TokenType::String, "hi \u{1F600}!".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new(r#""hi 😀!""#.into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_accented_identifier() {
+        let exp = vec![
+            Token::new(TokenType::Identifier, "café".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new("café".into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_greek_identifier() {
+        let exp = vec![
+            Token::new(TokenType::Identifier, "σ".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new("σ".into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
+    #[test]
+    fn scan_underscore_identifier() {
+        let exp = vec![
+            Token::new(TokenType::Identifier, "_foo_bar".into(), 1),
+            Token::new(TokenType::Eof, "".into(), 1),
+        ];
+        let act = Scanner::new("_foo_bar".into()).scan_tokens().unwrap();
+        assert_eq!(exp, act);
+    }
+
     #[test]
     fn scan_invalid_token_throws_error() {
         let token = "\0".to_string();
@@ -0,0 +1,4 @@
+/// Extract the substring of `chars` spanning the half-open codepoint range `[start, end)`.
+pub(crate) fn substring(chars: &[char], start: usize, end: usize) -> String {
+    chars.get(start..end).unwrap_or(&[]).iter().collect()
+}